@@ -0,0 +1,89 @@
+/*
+ * Copyright Cedar Contributors
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Maps Cedar binding errors onto the Java exception that should represent them across
+//! the JNI boundary, instead of panicking (`.unwrap()`/`.expect()`) or collapsing every
+//! failure into an empty `Optional` that can't tell malformed syntax from anything else.
+
+use crate::utils::Error;
+use jni::JNIEnv;
+
+/// A Cedar binding error, classified by which Java exception it should surface as
+#[derive(Debug)]
+pub enum JniException {
+    /// The input failed to parse as valid Cedar syntax; carries Cedar's own diagnostic
+    /// text so callers see the real parser error rather than a bare `Optional.empty()`
+    Parse(String),
+    /// A failure inside the JNI binding itself (object allocation, a missing method,
+    /// a JNI call erroring out) rather than a problem with caller-supplied data
+    Internal(String),
+    /// A required object reference was null
+    NullPointer(String),
+}
+
+impl JniException {
+    fn class_name(&self) -> &'static str {
+        match self {
+            Self::Parse(_) => "com/cedarpolicy/model/exception/CedarParseException",
+            Self::Internal(_) => "com/cedarpolicy/model/exception/InternalException",
+            Self::NullPointer(_) => "java/lang/NullPointerException",
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            Self::Parse(msg) | Self::Internal(msg) | Self::NullPointer(msg) => msg,
+        }
+    }
+
+    /// Throw this as a Java exception on `env`. Throwing doesn't unwind Rust -- it only
+    /// arms the exception the JVM raises once the native method returns -- so callers
+    /// still need to return a sentinel value immediately after.
+    pub fn throw(&self, env: &mut JNIEnv) {
+        // If throwing itself fails (e.g. the exception class isn't on the classpath)
+        // there's nothing left to do but give up quietly; we can't throw a second time.
+        let _ = env.throw_new(self.class_name(), self.message());
+    }
+}
+
+impl From<Error> for JniException {
+    fn from(e: Error) -> Self {
+        match e.downcast::<JniException>() {
+            Ok(e) => *e,
+            Err(e) => Self::Internal(e.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for JniException {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for JniException {}
+
+/// Run `result`, throwing the mapped Java exception and returning `sentinel` on `Err`
+/// instead of propagating the error or panicking.
+pub fn unwrap_or_throw<T>(env: &mut JNIEnv, result: Result<T, JniException>, sentinel: T) -> T {
+    match result {
+        Ok(v) => v,
+        Err(e) => {
+            e.throw(env);
+            sentinel
+        }
+    }
+}
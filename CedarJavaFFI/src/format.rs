@@ -0,0 +1,40 @@
+/*
+ * Copyright Cedar Contributors
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! JNI entry point for pretty-printing Cedar policy source, built on [`JFormatterConfig`].
+
+use crate::{exceptions::JniException, objects::JFormatterConfig};
+use cedar_java_macros::jni_export;
+use cedar_policy_formatter::policies_str_to_pretty;
+use jni::{objects::JString, JNIEnv};
+
+/// Pretty-print Cedar policy (or policy-set) source text according to `config`. Formatter
+/// errors (e.g. `policies_str` doesn't parse) surface as a `CedarParseException` rather
+/// than an empty or truncated result: returning `JniException::Parse` here only decides
+/// *which* exception class is right, and the `#[jni_export]` trampoline is what actually
+/// throws it via `JniException`'s class mapping (`jni_support::into_jni_return`).
+#[jni_export(class = "com.cedarpolicy.model.formatter.Formatter")]
+pub fn format_policies<'a>(
+    env: &mut JNIEnv<'a>,
+    policies_str: JString<'a>,
+    config: JFormatterConfig<'a>,
+) -> std::result::Result<String, JniException> {
+    let src = env
+        .get_string(&policies_str)
+        .map_err(|e| JniException::Internal(e.to_string()))?;
+    let src = String::from(src);
+    policies_str_to_pretty(&src, &config.get_rust_repr()).map_err(|e| JniException::Parse(e.to_string()))
+}
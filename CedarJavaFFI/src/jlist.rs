@@ -0,0 +1,140 @@
+/*
+ * Copyright Cedar Contributors
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Typed wrapper around `java.util.List`, plus helpers for moving its contents to and
+//! from Rust.
+
+use crate::{
+    objects::{FromJava, IntoJavaObject},
+    utils::Result,
+};
+use jni::{
+    objects::{JObject, JString, JValueGen},
+    sys::jint,
+    JNIEnv,
+};
+use std::marker::PhantomData;
+
+/// Typed wrapper around a `java.util.List<T>`. `T` is a marker for the element type the
+/// list is expected to hold; it isn't enforced by the JVM, only by how callers choose to
+/// read the list back out (see [`jobject_list_to_rust_vec`]).
+pub struct List<'a, T> {
+    obj: JObject<'a>,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T> List<'a, T> {
+    /// Construct a new, empty `java.util.ArrayList`
+    pub fn new(env: &mut JNIEnv<'a>) -> Result<Self> {
+        let obj = env.new_object("java/util/ArrayList", "()V", &[])?;
+        Ok(Self {
+            obj,
+            marker: PhantomData,
+        })
+    }
+
+    /// Wrap an existing `java.util.List` object without checking its element type
+    pub fn cast_unchecked(obj: JObject<'a>, _env: &mut JNIEnv<'a>) -> Result<Self> {
+        Ok(Self {
+            obj,
+            marker: PhantomData,
+        })
+    }
+
+    /// Number of elements in the list
+    pub fn size(&self, env: &mut JNIEnv<'a>) -> Result<usize> {
+        let size = env.call_method(&self.obj, "size", "()I", &[])?.i()?;
+        Ok(usize::try_from(size)?)
+    }
+
+    /// Get the element at `index` as an untyped object
+    pub fn get(&self, env: &mut JNIEnv<'a>, index: usize) -> Result<JObject<'a>> {
+        let index = jint::try_from(index)?;
+        Ok(env
+            .call_method(&self.obj, "get", "(I)Ljava/lang/Object;", &[JValueGen::Int(index)])?
+            .l()?)
+    }
+
+    /// Append `element` to the list
+    pub fn add<E: AsRef<JObject<'a>>>(&mut self, env: &mut JNIEnv<'a>, element: E) -> Result<()> {
+        env.call_method(
+            &self.obj,
+            "add",
+            "(Ljava/lang/Object;)Z",
+            &[JValueGen::Object(element.as_ref())],
+        )?;
+        Ok(())
+    }
+}
+
+impl<'a, T> AsRef<JObject<'a>> for List<'a, T> {
+    fn as_ref(&self) -> &JObject<'a> {
+        &self.obj
+    }
+}
+
+impl<'a, T> From<List<'a, T>> for JObject<'a> {
+    fn from(value: List<'a, T>) -> Self {
+        value.obj
+    }
+}
+
+/// An element type that can appear inside a [`List`]: a slash-separated Java class name
+/// (informational -- used for documentation/debugging, since `List` itself doesn't check
+/// it) plus a `FromJava`/`IntoJavaObject` pair describing how one element moves across
+/// the JNI boundary.
+pub trait ListElement<'a>: FromJava<'a, From = JObject<'a>> + IntoJavaObject<'a> {
+    /// Slash-separated Java class name of this element type
+    const CLASS: &'static str;
+}
+
+impl<'a> ListElement<'a> for String {
+    const CLASS: &'static str = "java/lang/String";
+}
+
+impl<'a> ListElement<'a> for JString<'a> {
+    const CLASS: &'static str = "java/lang/String";
+}
+
+/// Decode every element of a `java.util.List<T>` into a `T` via [`FromJava`]
+pub fn jobject_list_to_rust_vec<'a, T: ListElement<'a>>(
+    env: &mut JNIEnv<'a>,
+    list: &List<'a, T>,
+) -> Result<Vec<T>> {
+    let size = list.size(env)?;
+    let mut result = Vec::with_capacity(size);
+    for i in 0..size {
+        let elem = list.get(env, i)?;
+        if elem.is_null() {
+            return Err(format!("list element {i} of type {} was null", T::CLASS).into());
+        }
+        result.push(T::from_java(env, elem)?);
+    }
+    Ok(result)
+}
+
+/// Encode a `Vec<T>` into a new `java.util.List<T>`
+pub fn rust_vec_to_jlist<'a, T: ListElement<'a>>(
+    env: &mut JNIEnv<'a>,
+    items: Vec<T>,
+) -> Result<List<'a, T>> {
+    let mut list = List::new(env)?;
+    for item in items {
+        let obj = item.into_java_object(env)?;
+        list.add(env, obj)?;
+    }
+    Ok(list)
+}
@@ -0,0 +1,70 @@
+/*
+ * Copyright Cedar Contributors
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Runtime support the [`cedar_java_macros::jni_export`] attribute expands into. Kept
+//! separate from `objects` since, unlike the conversion traits there, this is glue the
+//! generated trampolines call rather than something wrapper authors implement by hand.
+
+use crate::{exceptions::JniException, objects::IntoJava};
+use jni::{
+    objects::{JObject, JString},
+    sys::jobject,
+    JNIEnv,
+};
+
+/// Narrow the various `IntoJava::To` representations our wrappers produce down to the
+/// single raw `jobject` pointer a `extern "system"` trampoline returns.
+pub trait IntoRawObject<'a> {
+    /// Consume `self` and return the raw, already-valid-for-the-JVM object pointer
+    fn into_raw_object(self) -> jobject;
+}
+
+impl<'a> IntoRawObject<'a> for JObject<'a> {
+    fn into_raw_object(self) -> jobject {
+        self.into_raw()
+    }
+}
+
+impl<'a> IntoRawObject<'a> for JString<'a> {
+    fn into_raw_object(self) -> jobject {
+        JObject::from(self).into_raw()
+    }
+}
+
+/// Run a `#[jni_export]`-wrapped function's result through `IntoJava` and narrow it to
+/// the raw `jobject` a trampoline returns. On `Err`, throws the Java exception class
+/// [`JniException`] maps `e` onto (e.g. a malformed-input `Parse` error reaches Java as a
+/// `CedarParseException`, not a generic failure) and returns a null sentinel so the
+/// native method can still return control to the JVM once the exception takes effect.
+pub fn into_jni_return<'a, T, E>(env: &mut JNIEnv<'a>, result: std::result::Result<T, E>) -> jobject
+where
+    T: IntoJava<'a>,
+    T::To: IntoRawObject<'a>,
+    E: Into<JniException>,
+{
+    let throw_and_null = |env: &mut JNIEnv<'a>, e: JniException| {
+        e.throw(env);
+        std::ptr::null_mut()
+    };
+
+    match result {
+        Ok(v) => match v.into_java(env) {
+            Ok(raw) => raw.into_raw_object(),
+            Err(e) => throw_and_null(env, JniException::from(e)),
+        },
+        Err(e) => throw_and_null(env, e.into()),
+    }
+}
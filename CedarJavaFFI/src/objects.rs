@@ -15,7 +15,8 @@
  */
 
 use crate::{
-    jlist::{jstr_list_to_rust_vec, List},
+    exceptions::JniException,
+    jlist::{jobject_list_to_rust_vec, rust_vec_to_jlist, List, ListElement},
     utils::{assert_is_class, get_object_ref, Result},
 };
 use std::{marker::PhantomData, str::FromStr};
@@ -24,7 +25,7 @@ use cedar_policy::{EntityId, EntityTypeName, EntityUid};
 use cedar_policy_formatter::Config;
 use jni::{
     objects::{JObject, JString, JValueGen, JValueOwned},
-    sys::jvalue,
+    sys::{jint, jvalue},
     JNIEnv,
 };
 
@@ -42,6 +43,97 @@ impl<'a> Object<'a> for JString<'a> {
     }
 }
 
+/// Decode a value out of its incoming JNI representation. This is the dual of
+/// [`IntoJava`], and together they're meant to replace type-by-type conversion methods
+/// (`Object::cast`, ad-hoc `try_from`/`new` pairs, `get_rust_repr`) with one symmetric
+/// vocabulary that generic code (lists, optionals, the `#[jni_export]` macro) can drive
+/// without per-type glue.
+pub trait FromJava<'a>: Sized {
+    /// The JNI representation `Self` is decoded from, e.g. `JObject`, `jstring`, `jint`
+    type From;
+
+    /// Decode `raw` into `Self`
+    fn from_java(env: &mut JNIEnv<'a>, raw: Self::From) -> Result<Self>;
+}
+
+/// Encode a value into its outgoing JNI representation. The dual of [`FromJava`].
+pub trait IntoJava<'a> {
+    /// The JNI representation `Self` is encoded into
+    type To;
+
+    /// Encode `self` into its JNI representation
+    fn into_java(self, env: &mut JNIEnv<'a>) -> Result<Self::To>;
+}
+
+/// Marker for wrapper types whose JNI representation is a plain [`JObject`]. Implementing
+/// this instead of [`IntoJava`] directly gives `Self` an `IntoJava<To = JObject<'a>>` impl
+/// for free, so most wrappers only need to say how to produce their backing object.
+pub trait IntoJavaObject<'a> {
+    /// Encode `self` into the [`JObject`] backing it
+    fn into_java_object(self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>>;
+}
+
+impl<'a, T: IntoJavaObject<'a>> IntoJava<'a> for T {
+    type To = JObject<'a>;
+
+    fn into_java(self, env: &mut JNIEnv<'a>) -> Result<Self::To> {
+        self.into_java_object(env)
+    }
+}
+
+impl<'a> FromJava<'a> for JString<'a> {
+    type From = JObject<'a>;
+
+    fn from_java(env: &mut JNIEnv<'a>, raw: Self::From) -> Result<Self> {
+        Self::cast(env, raw)
+    }
+}
+
+impl<'a> IntoJavaObject<'a> for JString<'a> {
+    fn into_java_object(self, _env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        Ok(self.into())
+    }
+}
+
+impl<'a> FromJava<'a> for String {
+    type From = JObject<'a>;
+
+    fn from_java(env: &mut JNIEnv<'a>, raw: Self::From) -> Result<Self> {
+        let jstr = JString::cast(env, raw)?;
+        Ok(String::from(env.get_string(&jstr)?))
+    }
+}
+
+impl<'a> IntoJavaObject<'a> for String {
+    fn into_java_object(self, env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        Ok(env.new_string(self)?.into())
+    }
+}
+
+impl<'a, T: AsRef<JObject<'a>>> IntoJava<'a> for Option<T> {
+    type To = JOptional<'a, T>;
+
+    fn into_java(self, env: &mut JNIEnv<'a>) -> Result<Self::To> {
+        JOptional::from_optional(env, self)
+    }
+}
+
+impl<'a> FromJava<'a> for usize {
+    type From = jint;
+
+    fn from_java(_env: &mut JNIEnv<'a>, raw: Self::From) -> Result<Self> {
+        Ok(Self::try_from(raw)?)
+    }
+}
+
+impl<'a> FromJava<'a> for isize {
+    type From = jint;
+
+    fn from_java(_env: &mut JNIEnv<'a>, raw: Self::From) -> Result<Self> {
+        Ok(Self::try_from(raw)?)
+    }
+}
+
 /// Typed wrapper around EntityTypeNames
 /// (com.cedarpolicy.value.EntityTypeName)
 pub struct JEntityTypeName<'a> {
@@ -54,11 +146,11 @@ impl<'a> JEntityTypeName<'a> {
     pub fn new(
         env: &mut JNIEnv<'a>,
         basename: JString<'a>,
-        namespace: List<'a, JString<'a>>,
+        namespace: List<'a, String>,
     ) -> Result<Self> {
         let jstr_basename = env.get_string(&basename)?;
         let basename_str = String::from(jstr_basename);
-        let mut full_type_name: Vec<String> = jstr_list_to_rust_vec(env, &namespace)?;
+        let mut full_type_name: Vec<String> = jobject_list_to_rust_vec(env, &namespace)?;
         full_type_name.push(basename_str);
         let has_namespace_component_with_colon = full_type_name.iter().any(|s| s.contains("::"));
         if has_namespace_component_with_colon {
@@ -66,16 +158,14 @@ impl<'a> JEntityTypeName<'a> {
         }
         let full_ns_str: String = full_type_name.join("::");
         let type_name: EntityTypeName = full_ns_str.parse()?;
-        let obj = env
-            .new_object(
-                "com/cedarpolicy/value/EntityTypeName",
-                "(Ljava/util/List;Ljava/lang/String;)V",
-                &[
-                    JValueGen::Object(namespace.as_ref()),
-                    JValueGen::Object(basename.as_ref()),
-                ],
-            )
-            .unwrap();
+        let obj = env.new_object(
+            "com/cedarpolicy/value/EntityTypeName",
+            "(Ljava/util/List;Ljava/lang/String;)V",
+            &[
+                JValueGen::Object(namespace.as_ref()),
+                JValueGen::Object(basename.as_ref()),
+            ],
+        )?;
         Ok(Self { obj, type_name })
     }
 
@@ -90,7 +180,7 @@ impl<'a> JEntityTypeName<'a> {
     }
 
     /// Get the namespace field
-    pub fn get_namespace(&self, env: &mut JNIEnv<'a>) -> Result<List<'a, JString<'a>>> {
+    pub fn get_namespace(&self, env: &mut JNIEnv<'a>) -> Result<List<'a, String>> {
         let v = env.call_method(&self.obj, "getNamespace", "()Ljava/util/List;", &[])?;
         List::cast_unchecked(get_object_ref(v)?, env)
     }
@@ -104,29 +194,27 @@ impl<'a> JEntityTypeName<'a> {
     /// Given a rust EntityTypeName, allocate a new Java EntityTypeName object
     pub fn try_from(env: &mut JNIEnv<'a>, etype: &EntityTypeName) -> Result<Self> {
         let basename = env.new_string(etype.basename())?;
-        let mut namespace_array = List::new(env)?;
-        for part in etype.namespace_components() {
-            let part_str = env.new_string(part)?;
-            namespace_array.add(env, part_str)?;
-        }
+        let namespace: Vec<String> = etype.namespace_components().map(String::from).collect();
+        let namespace_array = rust_vec_to_jlist(env, namespace)?;
 
         JEntityTypeName::new(env, basename, namespace_array)
     }
 
-    /// Attempt to parse an EntityTypeName from a string, and allocate the result as a Java object
-    pub fn parse(env: &mut JNIEnv<'a>, src: &str) -> Result<JOptional<'a, Self>> {
-        match EntityTypeName::from_str(src) {
-            Ok(etype) => {
-                let jetype = Self::try_from(env, &etype)?;
-                JOptional::of(env, jetype)
-            }
-            Err(_) => JOptional::empty(env),
-        }
+    /// Attempt to parse an EntityTypeName from a string, and allocate the result as a Java
+    /// object. Unlike the old `JOptional::empty`-on-any-error behavior, a malformed
+    /// `src` surfaces as a `CedarParseException` carrying Cedar's own diagnostic instead
+    /// of being indistinguishable from any other failure.
+    pub fn parse(env: &mut JNIEnv<'a>, src: &str) -> std::result::Result<Self, JniException> {
+        let etype = EntityTypeName::from_str(src).map_err(|e| JniException::Parse(e.to_string()))?;
+        Self::try_from(env, &etype).map_err(JniException::from)
     }
 }
 
 impl<'a> Object<'a> for JEntityTypeName<'a> {
     fn cast(env: &mut JNIEnv<'a>, obj: JObject<'a>) -> Result<Self> {
+        if obj.is_null() {
+            return Err(JniException::NullPointer("expected an EntityTypeName, got null".into()).into());
+        }
         assert_is_class(env, &obj, "com/cedarpolicy/value/EntityTypeName")?;
         let namespace = env.call_method(&obj, "getNamespace", "()Ljava/util/List;", &[])?;
         let namespace_components = List::cast_unchecked(get_object_ref(namespace)?, env)?;
@@ -134,7 +222,7 @@ impl<'a> Object<'a> for JEntityTypeName<'a> {
         let basename = JString::cast(env, get_object_ref(basename)?)?;
         let jstr_basename = env.get_string(&basename)?;
         let basename_str = String::from(jstr_basename);
-        let mut full_type_name: Vec<String> = jstr_list_to_rust_vec(env, &namespace_components)?;
+        let mut full_type_name: Vec<String> = jobject_list_to_rust_vec(env, &namespace_components)?;
         full_type_name.push(basename_str);
         let has_namespace_component_with_colon = full_type_name.iter().any(|s| s.contains("::"));
         if has_namespace_component_with_colon {
@@ -146,6 +234,24 @@ impl<'a> Object<'a> for JEntityTypeName<'a> {
     }
 }
 
+impl<'a> FromJava<'a> for JEntityTypeName<'a> {
+    type From = JObject<'a>;
+
+    fn from_java(env: &mut JNIEnv<'a>, raw: Self::From) -> Result<Self> {
+        Self::cast(env, raw)
+    }
+}
+
+impl<'a> IntoJavaObject<'a> for JEntityTypeName<'a> {
+    fn into_java_object(self, _env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        Ok(self.obj)
+    }
+}
+
+impl<'a> ListElement<'a> for JEntityTypeName<'a> {
+    const CLASS: &'static str = "com/cedarpolicy/value/EntityTypeName";
+}
+
 impl<'a> From<JEntityTypeName<'a>> for JObject<'a> {
     fn from(value: JEntityTypeName<'a>) -> Self {
         value.obj
@@ -258,6 +364,9 @@ impl<'a> JEntityId<'a> {
 
 impl<'a> Object<'a> for JEntityId<'a> {
     fn cast(env: &mut JNIEnv<'a>, obj: JObject<'a>) -> Result<Self> {
+        if obj.is_null() {
+            return Err(JniException::NullPointer("expected an EntityIdentifier, got null".into()).into());
+        }
         assert_is_class(env, &obj, "com/cedarpolicy/value/EntityIdentifier")?;
         let v = env.call_method(&obj, "getId", "()Ljava/lang/String;", &[])?;
         let id_field = get_object_ref(v)?;
@@ -277,6 +386,24 @@ impl<'a> AsRef<JObject<'a>> for JEntityId<'a> {
     }
 }
 
+impl<'a> FromJava<'a> for JEntityId<'a> {
+    type From = JObject<'a>;
+
+    fn from_java(env: &mut JNIEnv<'a>, raw: Self::From) -> Result<Self> {
+        Self::cast(env, raw)
+    }
+}
+
+impl<'a> IntoJavaObject<'a> for JEntityId<'a> {
+    fn into_java_object(self, _env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        Ok(self.obj)
+    }
+}
+
+impl<'a> ListElement<'a> for JEntityId<'a> {
+    const CLASS: &'static str = "com/cedarpolicy/value/EntityIdentifier";
+}
+
 /// Typed wrapper for Entity UIDs
 /// (com.cedarpolicy.value.EntityUID)
 pub struct JEntityUID<'a> {
@@ -301,23 +428,25 @@ impl<'a> JEntityUID<'a> {
         Ok(Self { obj })
     }
 
-    /// Attempt to parse an EntityUID from a string, and return the result as a Java optional
-    pub fn parse(env: &mut JNIEnv<'a>, src: &str) -> Result<JOptional<'a, Self>> {
-        let r: std::result::Result<EntityUid, _> = src.parse();
-        match r {
-            Ok(eid) => {
-                let id = JEntityId::try_from(env, eid.id())?;
-                let entity_type = JEntityTypeName::try_from(env, eid.type_name())?;
-                let obj = Self::new(env, entity_type, id)?;
-                JOptional::of(env, obj)
-            }
-            Err(_) => JOptional::empty(env),
-        }
+    /// Attempt to parse an EntityUID from a string. A malformed `src` surfaces as a
+    /// `CedarParseException` carrying Cedar's own diagnostic rather than an empty
+    /// `Optional` a caller can't distinguish from any other kind of failure.
+    pub fn parse(env: &mut JNIEnv<'a>, src: &str) -> std::result::Result<Self, JniException> {
+        let eid: EntityUid = src.parse().map_err(|e: cedar_policy::ParseErrors| JniException::Parse(e.to_string()))?;
+        (|| -> Result<Self> {
+            let id = JEntityId::try_from(env, eid.id())?;
+            let entity_type = JEntityTypeName::try_from(env, eid.type_name())?;
+            Self::new(env, entity_type, id)
+        })()
+        .map_err(JniException::from)
     }
 }
 
 impl<'a> Object<'a> for JEntityUID<'a> {
     fn cast(env: &mut JNIEnv<'a>, obj: JObject<'a>) -> Result<Self> {
+        if obj.is_null() {
+            return Err(JniException::NullPointer("expected an EntityUID, got null".into()).into());
+        }
         assert_is_class(env, &obj, "com/cedarpolicy/value/EntityUID")?;
         Ok(Self { obj })
     }
@@ -329,6 +458,24 @@ impl<'a> AsRef<JObject<'a>> for JEntityUID<'a> {
     }
 }
 
+impl<'a> FromJava<'a> for JEntityUID<'a> {
+    type From = JObject<'a>;
+
+    fn from_java(env: &mut JNIEnv<'a>, raw: Self::From) -> Result<Self> {
+        Self::cast(env, raw)
+    }
+}
+
+impl<'a> IntoJavaObject<'a> for JEntityUID<'a> {
+    fn into_java_object(self, _env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        Ok(self.obj)
+    }
+}
+
+impl<'a> ListElement<'a> for JEntityUID<'a> {
+    const CLASS: &'static str = "com/cedarpolicy/value/EntityUID";
+}
+
 /// Typed wrapper for Policy objects
 /// (com.cedarpolicy.model.policy.Policy)
 pub struct JPolicy<'a> {
@@ -342,16 +489,14 @@ impl<'a> JPolicy<'a> {
         policy_string: &JString,
         policy_id_string: &JString,
     ) -> Result<Self> {
-        let obj = env
-            .new_object(
-                "com/cedarpolicy/model/policy/Policy",
-                "(Ljava/lang/String;Ljava/lang/String;)V",
-                &[
-                    JValueGen::Object(policy_string),
-                    JValueGen::Object(policy_id_string),
-                ],
-            )
-            .expect("Failed to create new Policy object");
+        let obj = env.new_object(
+            "com/cedarpolicy/model/policy/Policy",
+            "(Ljava/lang/String;Ljava/lang/String;)V",
+            &[
+                JValueGen::Object(policy_string),
+                JValueGen::Object(policy_id_string),
+            ],
+        )?;
 
         Ok(Self { obj })
     }
@@ -359,6 +504,9 @@ impl<'a> JPolicy<'a> {
 
 impl<'a> Object<'a> for JPolicy<'a> {
     fn cast(env: &mut JNIEnv<'a>, obj: JObject<'a>) -> Result<Self> {
+        if obj.is_null() {
+            return Err(JniException::NullPointer("expected a Policy, got null".into()).into());
+        }
         assert_is_class(env, &obj, "com/cedarpolicy/model/policy/Policy")?;
         Ok(Self { obj })
     }
@@ -370,6 +518,24 @@ impl<'a> AsRef<JObject<'a>> for JPolicy<'a> {
     }
 }
 
+impl<'a> FromJava<'a> for JPolicy<'a> {
+    type From = JObject<'a>;
+
+    fn from_java(env: &mut JNIEnv<'a>, raw: Self::From) -> Result<Self> {
+        Self::cast(env, raw)
+    }
+}
+
+impl<'a> IntoJavaObject<'a> for JPolicy<'a> {
+    fn into_java_object(self, _env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        Ok(self.obj)
+    }
+}
+
+impl<'a> ListElement<'a> for JPolicy<'a> {
+    const CLASS: &'static str = "com/cedarpolicy/model/policy/Policy";
+}
+
 pub struct JFormatterConfig<'a> {
     obj: JObject<'a>,
     formatter_config: Config,
@@ -389,12 +555,15 @@ impl<'a> AsRef<JObject<'a>> for JFormatterConfig<'a> {
 
 impl<'a> Object<'a> for JFormatterConfig<'a> {
     fn cast(env: &mut JNIEnv<'a>, obj: JObject<'a>) -> Result<Self> {
+        if obj.is_null() {
+            return Err(JniException::NullPointer("expected a formatter Config, got null".into()).into());
+        }
         assert_is_class(env, &obj, "com/cedarpolicy/model/formatter/Config")?;
         let line_width_jint = env.call_method(&obj, "getLineWidth", "()I", &[])?.i()?;
         let indent_width_jint = env.call_method(&obj, "getIndentWidth", "()I", &[])?.i()?;
         let formatter_config = Config {
-            line_width: usize::try_from(line_width_jint)?,
-            indent_width: isize::try_from(indent_width_jint)?,
+            line_width: usize::from_java(env, line_width_jint)?,
+            indent_width: isize::from_java(env, indent_width_jint)?,
         };
         Ok(Self {
             obj,
@@ -402,3 +571,17 @@ impl<'a> Object<'a> for JFormatterConfig<'a> {
         })
     }
 }
+
+impl<'a> FromJava<'a> for JFormatterConfig<'a> {
+    type From = JObject<'a>;
+
+    fn from_java(env: &mut JNIEnv<'a>, raw: Self::From) -> Result<Self> {
+        Self::cast(env, raw)
+    }
+}
+
+impl<'a> IntoJavaObject<'a> for JFormatterConfig<'a> {
+    fn into_java_object(self, _env: &mut JNIEnv<'a>) -> Result<JObject<'a>> {
+        Ok(self.obj)
+    }
+}
@@ -0,0 +1,196 @@
+/*
+ * Copyright Cedar Contributors
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Proc-macro that generates the `Java_com_cedarpolicy_*` JNI trampolines so wrapper
+//! authors only have to write the typed business logic.
+//!
+//! `#[jni_export(class = "com.cedarpolicy.value.EntityTypeName")]` applied to a function
+//! emits a second, `#[no_mangle] extern "system"` function alongside the original: its
+//! name is the mangled JNI symbol for `class` + the function's name, its parameters are
+//! each param type's `FromJava` raw representation, and its body converts every
+//! parameter, calls the original function, and converts the result back through
+//! `cedar_java_ffi::jni_support::into_jni_return` -- which also turns an `Err` into a
+//! thrown Java exception instead of a panic or UB crossing the FFI boundary.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, Ident, ItemFn, LitStr, Token,
+};
+
+/// Parsed `#[jni_export(class = "...")]` attribute arguments
+struct JniExportArgs {
+    class: LitStr,
+}
+
+impl Parse for JniExportArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        if key != "class" {
+            return Err(syn::Error::new(key.span(), "expected `class = \"...\"`"));
+        }
+        input.parse::<Token![=]>()?;
+        let class: LitStr = input.parse()?;
+        Ok(Self { class })
+    }
+}
+
+/// Mangle a fully-qualified Java name fragment per the JNI spec: `_` becomes `_1`, `;`
+/// becomes `_2`, `[` becomes `_3`, `.`/`/` become `_`, and any other character that isn't
+/// an ASCII letter or digit falls back to the `_0xxxx` unicode escape.
+fn mangle(segment: &str) -> String {
+    let mut mangled = String::with_capacity(segment.len());
+    for c in segment.chars() {
+        match c {
+            '.' | '/' => mangled.push('_'),
+            '_' => mangled.push_str("_1"),
+            ';' => mangled.push_str("_2"),
+            '[' => mangled.push_str("_3"),
+            c if c.is_ascii_alphanumeric() => mangled.push(c),
+            c => mangled.push_str(&format!("_0{:04x}", c as u32)),
+        }
+    }
+    mangled
+}
+
+/// Build the `Java_...` symbol name for `class.method`
+fn jni_symbol_name(class: &str, method: &str) -> Ident {
+    format_ident!("Java_{}_{}", mangle(class), mangle(method))
+}
+
+#[proc_macro_attribute]
+pub fn jni_export(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as JniExportArgs);
+    let func = parse_macro_input!(item as ItemFn);
+
+    let class = args.class.value();
+    let fn_name = &func.sig.ident;
+    let symbol = jni_symbol_name(&class, &fn_name.to_string());
+
+    if matches!(func.sig.output, syn::ReturnType::Default) {
+        return syn::Error::new(Span::call_site(), "jni_export functions must return a Result")
+            .to_compile_error()
+            .into();
+    }
+
+    // The trampoline must reuse whatever lifetime name the wrapped function's own
+    // parameter types carry (e.g. `JString<'a>`): splicing those types as-is into a
+    // trampoline generic over a *different* lifetime (`'local`) would reference an
+    // undeclared lifetime. Fall back to `'local` only if the function declares none.
+    let lifetime = func
+        .sig
+        .generics
+        .lifetimes()
+        .next()
+        .map(|lt| lt.lifetime.clone())
+        .unwrap_or_else(|| syn::Lifetime::new("'local", Span::call_site()));
+
+    // Every parameter other than the leading `&mut JNIEnv` and an optional `JClass`/
+    // `JObject` receiver right after it is converted through `FromJava`: the trampoline
+    // takes each parameter's raw JNI representation and decodes it before calling
+    // through to the wrapped function. A receiver, if declared, is threaded straight
+    // through unconverted -- it already *is* the raw type JNI hands the trampoline.
+    let mut raw_params = Vec::new();
+    let mut convert_stmts = Vec::new();
+    let mut call_args = Vec::new();
+    let mut saw_env = false;
+    let mut saw_receiver = false;
+    // True only for the single parameter immediately following `&mut JNIEnv`, since
+    // that's the sole position JNI ever puts a receiver in.
+    let mut at_receiver_position = false;
+
+    for input in &func.sig.inputs {
+        let syn::FnArg::Typed(pat_ty) = input else {
+            return syn::Error::new_spanned(input, "jni_export does not support `self` receivers")
+                .to_compile_error()
+                .into();
+        };
+        let pat = &pat_ty.pat;
+        let ty = &pat_ty.ty;
+
+        if !saw_env && is_jnienv(ty) {
+            saw_env = true;
+            at_receiver_position = true;
+            call_args.push(quote! { &mut env });
+            continue;
+        }
+
+        if at_receiver_position && is_jni_receiver(ty) {
+            saw_receiver = true;
+            raw_params.push(quote! { #pat: #ty });
+            call_args.push(quote! { #pat });
+            at_receiver_position = false;
+            continue;
+        }
+        at_receiver_position = false;
+
+        raw_params.push(quote! { #pat: <#ty as ::cedar_java_ffi::objects::FromJava<#lifetime>>::From });
+        convert_stmts.push(quote! {
+            let #pat = <#ty as ::cedar_java_ffi::objects::FromJava<#lifetime>>::from_java(&mut env, #pat)?;
+        });
+        call_args.push(quote! { #pat });
+    }
+
+    // JNI always hands the trampoline a receiver (a `JClass` for a static native method,
+    // a `JObject` for an instance one) as its second parameter; synthesize an unused one
+    // if the wrapped function didn't ask to receive it.
+    if !saw_receiver {
+        raw_params.insert(0, quote! { _class: ::jni::objects::JClass<#lifetime> });
+    }
+
+    let expanded = quote! {
+        #func
+
+        #[no_mangle]
+        pub extern "system" fn #symbol<#lifetime>(
+            mut env: ::jni::JNIEnv<#lifetime>,
+            #(#raw_params),*
+        ) -> ::jni::sys::jobject {
+            let result = (|| {
+                #(#convert_stmts)*
+                #fn_name(#(#call_args),*)
+            })();
+            ::cedar_java_ffi::jni_support::into_jni_return(&mut env, result)
+        }
+    };
+
+    expanded.into()
+}
+
+fn is_jnienv(ty: &syn::Type) -> bool {
+    let syn::Type::Reference(r) = ty else {
+        return false;
+    };
+    let syn::Type::Path(p) = &*r.elem else {
+        return false;
+    };
+    p.path.segments.last().is_some_and(|seg| seg.ident == "JNIEnv")
+}
+
+/// Whether `ty` is the receiver JNI passes every native method -- `JClass` for a static
+/// method, `JObject` for an instance method -- which a wrapped function can opt into
+/// receiving verbatim (no `FromJava` conversion) by declaring it right after `&mut JNIEnv`.
+fn is_jni_receiver(ty: &syn::Type) -> bool {
+    let syn::Type::Path(p) = ty else {
+        return false;
+    };
+    p.path
+        .segments
+        .last()
+        .is_some_and(|seg| seg.ident == "JClass" || seg.ident == "JObject")
+}